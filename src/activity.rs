@@ -0,0 +1,104 @@
+use serde::{Deserialize, Serialize};
+
+/// A single Garmin activity, as emitted by the Python client's `activities`
+/// data type.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Activity {
+    pub id: String,
+    pub sport: String,
+    pub start_time: String,
+    pub distance_m: f64,
+    pub duration_s: f64,
+    pub avg_hr: Option<f64>,
+}
+
+impl Activity {
+    fn csv_row(&self) -> String {
+        format!(
+            "{},{},{},{},{},{}",
+            self.id,
+            self.sport,
+            self.start_time,
+            self.distance_m,
+            self.duration_s,
+            self.avg_hr.map(|hr| hr.to_string()).unwrap_or_default(),
+        )
+    }
+}
+
+/// Aggregate health metrics for a day, as emitted by the Python client's
+/// `health`/`stats` data types.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct HealthStats {
+    pub date: String,
+    pub resting_hr: Option<f64>,
+    pub steps: Option<u64>,
+    pub sleep_s: Option<f64>,
+}
+
+impl HealthStats {
+    fn csv_row(&self) -> String {
+        format!(
+            "{},{},{},{}",
+            self.date,
+            self.resting_hr.map(|hr| hr.to_string()).unwrap_or_default(),
+            self.steps.map(|s| s.to_string()).unwrap_or_default(),
+            self.sleep_s.map(|s| s.to_string()).unwrap_or_default(),
+        )
+    }
+}
+
+pub fn print_activities_table(activities: &[Activity]) {
+    println!(
+        "{:<24} {:<12} {:<20} {:>10} {:>10} {:>8}",
+        "id", "sport", "start_time", "dist_m", "dur_s", "avg_hr"
+    );
+    for activity in activities {
+        println!(
+            "{:<24} {:<12} {:<20} {:>10.1} {:>10.1} {:>8}",
+            activity.id,
+            activity.sport,
+            activity.start_time,
+            activity.distance_m,
+            activity.duration_s,
+            activity
+                .avg_hr
+                .map(|hr| format!("{hr:.0}"))
+                .unwrap_or_else(|| "-".to_string()),
+        );
+    }
+}
+
+pub fn print_activities_csv(activities: &[Activity]) {
+    println!("id,sport,start_time,distance_m,duration_s,avg_hr");
+    for activity in activities {
+        println!("{}", activity.csv_row());
+    }
+}
+
+pub fn print_health_table(stats: &[HealthStats]) {
+    println!(
+        "{:<12} {:>10} {:>8} {:>10}",
+        "date", "resting_hr", "steps", "sleep_s"
+    );
+    for day in stats {
+        println!(
+            "{:<12} {:>10} {:>8} {:>10}",
+            day.date,
+            day.resting_hr
+                .map(|hr| format!("{hr:.0}"))
+                .unwrap_or_else(|| "-".to_string()),
+            day.steps.map(|s| s.to_string()).unwrap_or_else(|| "-".to_string()),
+            day.sleep_s
+                .map(|s| format!("{s:.0}"))
+                .unwrap_or_else(|| "-".to_string()),
+        );
+    }
+}
+
+pub fn print_health_csv(stats: &[HealthStats]) {
+    println!("date,resting_hr,steps,sleep_s");
+    for day in stats {
+        println!("{}", day.csv_row());
+    }
+}
@@ -0,0 +1,171 @@
+use anyhow::{bail, Context, Result};
+use std::ffi::OsStr;
+use std::fs;
+use std::io::{self, Write};
+use std::path::PathBuf;
+use std::time::{Duration, SystemTime};
+
+use crate::config;
+use crate::python;
+use crate::runner::{run_command, OutputMode};
+
+/// Path to the persisted session token.
+fn token_path() -> Result<PathBuf> {
+    let dir = config::config_dir().context("could not determine a config directory (no $XDG_CONFIG_HOME or $HOME)")?;
+    Ok(dir.join("token"))
+}
+
+/// Prompts for a Garmin username and password, exchanges them for a session
+/// token via the Python client, and persists only the token.
+pub fn login() -> Result<()> {
+    print!("Garmin username: ");
+    io::stdout().flush()?;
+    let mut username = String::new();
+    io::stdin().read_line(&mut username)?;
+    let username = username.trim();
+
+    let password = rpassword::prompt_password("Garmin password: ")
+        .context("failed to read password from terminal")?;
+
+    let python = python::find_python()?;
+    let output = run_command(
+        python.as_os_str(),
+        &[
+            OsStr::new("python_client/example.py"),
+            OsStr::new("--authenticate"),
+        ],
+        None,
+        &[
+            ("GARMIN_COACH_USERNAME", username),
+            ("GARMIN_COACH_PASSWORD", password.as_str()),
+        ],
+        OutputMode::Capture,
+    )?;
+
+    let token = String::from_utf8_lossy(&output.stdout).trim().to_string();
+    if token.is_empty() {
+        bail!("authentication succeeded but no token was returned");
+    }
+
+    store_token(&token)?;
+    println!("✅ Logged in and stored session token.");
+
+    Ok(())
+}
+
+/// Removes the stored session token, if any.
+pub fn logout() -> Result<()> {
+    let path = token_path()?;
+    if path.is_file() {
+        fs::remove_file(&path).with_context(|| format!("failed to remove {}", path.display()))?;
+        println!("Logged out.");
+    } else {
+        println!("Not logged in.");
+    }
+    Ok(())
+}
+
+/// Reports whether a session token is stored and how old it is.
+///
+/// This only checks presence on disk; it does not contact Garmin Connect to
+/// confirm the token is still accepted, so a stale or revoked token still
+/// reports as present.
+pub fn status() -> Result<()> {
+    let path = token_path()?;
+    if !path.is_file() {
+        println!("No token stored.");
+        return Ok(());
+    }
+
+    let metadata = fs::metadata(&path).with_context(|| format!("failed to stat {}", path.display()))?;
+    let age = metadata
+        .modified()
+        .ok()
+        .and_then(|modified| SystemTime::now().duration_since(modified).ok())
+        .unwrap_or(Duration::ZERO);
+
+    println!(
+        "Token present (age: {}) — not verified against Garmin Connect.",
+        format_duration(age)
+    );
+    Ok(())
+}
+
+/// Writes `token` to the token file, creating the config directory if needed.
+/// On Unix a newly created file gets `0600` permissions (owner read/write
+/// only); on other platforms the file is written with default permissions.
+fn store_token(token: &str) -> Result<()> {
+    let path = token_path()?;
+    if let Some(dir) = path.parent() {
+        fs::create_dir_all(dir).with_context(|| format!("failed to create {}", dir.display()))?;
+    }
+
+    write_private_file(&path, token)?;
+    Ok(())
+}
+
+#[cfg(unix)]
+fn write_private_file(path: &std::path::Path, contents: &str) -> Result<()> {
+    use std::fs::OpenOptions;
+    use std::os::unix::fs::OpenOptionsExt;
+
+    let mut file = OpenOptions::new()
+        .write(true)
+        .create(true)
+        .truncate(true)
+        .mode(0o600)
+        .open(path)
+        .with_context(|| format!("failed to create {}", path.display()))?;
+    file.write_all(contents.as_bytes())?;
+    Ok(())
+}
+
+#[cfg(not(unix))]
+fn write_private_file(path: &std::path::Path, contents: &str) -> Result<()> {
+    fs::write(path, contents).with_context(|| format!("failed to create {}", path.display()))
+}
+
+fn format_duration(duration: Duration) -> String {
+    let secs = duration.as_secs();
+    if secs < 60 {
+        return format!("{secs}s");
+    }
+    let mins = secs / 60;
+    if mins < 60 {
+        return format!("{mins}m");
+    }
+    let hours = mins / 60;
+    if hours < 24 {
+        return format!("{hours}h");
+    }
+    format!("{}d", hours / 24)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn format_duration_seconds() {
+        assert_eq!(format_duration(Duration::from_secs(0)), "0s");
+        assert_eq!(format_duration(Duration::from_secs(59)), "59s");
+    }
+
+    #[test]
+    fn format_duration_minutes() {
+        assert_eq!(format_duration(Duration::from_secs(60)), "1m");
+        assert_eq!(format_duration(Duration::from_secs(3599)), "59m");
+    }
+
+    #[test]
+    fn format_duration_hours() {
+        assert_eq!(format_duration(Duration::from_secs(3600)), "1h");
+        assert_eq!(format_duration(Duration::from_secs(86399)), "23h");
+    }
+
+    #[test]
+    fn format_duration_days() {
+        assert_eq!(format_duration(Duration::from_secs(86400)), "1d");
+        assert_eq!(format_duration(Duration::from_secs(2 * 86400)), "2d");
+    }
+}
@@ -0,0 +1,34 @@
+use anyhow::{Context, Result};
+use std::fs;
+use std::path::PathBuf;
+
+use crate::config;
+
+/// Path for the cached JSON payload of `data_type` on `date` (e.g.
+/// `"activities"`, `"2026-07-26"`).
+fn cache_path(data_type: &str, date: &str) -> Result<PathBuf> {
+    let dir = config::config_dir()
+        .context("could not determine a config directory (no $XDG_CONFIG_HOME or $HOME)")?
+        .join("cache");
+    Ok(dir.join(format!("{data_type}-{date}.json")))
+}
+
+/// Loads a previously cached payload, if one exists for `data_type`/`date`.
+pub fn load(data_type: &str, date: &str) -> Result<Option<String>> {
+    let path = cache_path(data_type, date)?;
+    if !path.is_file() {
+        return Ok(None);
+    }
+    let contents =
+        fs::read_to_string(&path).with_context(|| format!("failed to read {}", path.display()))?;
+    Ok(Some(contents))
+}
+
+/// Caches `payload` for `data_type`/`date`, overwriting any existing entry.
+pub fn store(data_type: &str, date: &str, payload: &str) -> Result<()> {
+    let path = cache_path(data_type, date)?;
+    if let Some(dir) = path.parent() {
+        fs::create_dir_all(dir).with_context(|| format!("failed to create {}", dir.display()))?;
+    }
+    fs::write(&path, payload).with_context(|| format!("failed to write {}", path.display()))
+}
@@ -0,0 +1,151 @@
+use anyhow::{Context, Result};
+use serde::Deserialize;
+use std::env;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+const ENV_GARMIN_USERNAME: &str = "GARMIN_COACH_USERNAME";
+const ENV_AI_PROVIDER: &str = "GARMIN_COACH_AI_PROVIDER";
+const ENV_AI_API_KEY: &str = "GARMIN_COACH_AI_API_KEY";
+const ENV_AI_MODEL: &str = "GARMIN_COACH_AI_MODEL";
+
+/// Garmin Connect and AI coaching provider settings, loaded from a config file.
+#[derive(Debug, Default, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct Config {
+    pub garmin_username: Option<String>,
+    pub ai_provider: Option<String>,
+    pub ai_api_key: Option<String>,
+    pub ai_model: Option<String>,
+}
+
+/// A `Config` together with the path it was loaded from, if any.
+pub struct LoadedConfig {
+    pub config: Config,
+    pub source: Option<PathBuf>,
+}
+
+/// Locates and loads the config file.
+///
+/// Searches `$XDG_CONFIG_HOME/garmin-coach/config.toml` first, then
+/// `./config.toml` in the current directory. Returns a default, sourceless
+/// config if neither exists.
+pub fn load() -> Result<LoadedConfig> {
+    for path in search_paths() {
+        if path.is_file() {
+            let contents = fs::read_to_string(&path)
+                .with_context(|| format!("failed to read config at {}", path.display()))?;
+            let config: Config = toml::from_str(&contents)
+                .with_context(|| format!("invalid config at {}", path.display()))?;
+            return Ok(LoadedConfig {
+                config,
+                source: Some(path),
+            });
+        }
+    }
+
+    Ok(LoadedConfig {
+        config: Config::default(),
+        source: None,
+    })
+}
+
+fn search_paths() -> Vec<PathBuf> {
+    let mut paths = Vec::new();
+
+    if let Some(dir) = config_dir() {
+        paths.push(dir.join("config.toml"));
+    }
+
+    paths.push(PathBuf::from("config.toml"));
+    paths
+}
+
+/// The directory holding `garmin-coach`'s config and stored auth token, e.g.
+/// `$XDG_CONFIG_HOME/garmin-coach` or `~/.config/garmin-coach`.
+pub fn config_dir() -> Option<PathBuf> {
+    let base = env::var_os("XDG_CONFIG_HOME")
+        .map(PathBuf::from)
+        .or_else(|| env::var_os("HOME").map(|home| Path::new(&home).join(".config")))?;
+    Some(base.join("garmin-coach"))
+}
+
+impl Config {
+    /// Environment variables to pass to the spawned Python client, reflecting
+    /// whichever fields are set.
+    pub fn env_vars(&self) -> Vec<(&'static str, String)> {
+        let mut vars = Vec::new();
+        if let Some(value) = &self.garmin_username {
+            vars.push((ENV_GARMIN_USERNAME, value.clone()));
+        }
+        if let Some(value) = &self.ai_provider {
+            vars.push((ENV_AI_PROVIDER, value.clone()));
+        }
+        if let Some(value) = &self.ai_api_key {
+            vars.push((ENV_AI_API_KEY, value.clone()));
+        }
+        if let Some(value) = &self.ai_model {
+            vars.push((ENV_AI_MODEL, value.clone()));
+        }
+        vars
+    }
+}
+
+/// Masks a secret for display, keeping only a short prefix.
+fn mask(value: &str) -> String {
+    let prefix: String = value.chars().take(4).collect();
+    if value.chars().count() <= 4 {
+        "****".to_string()
+    } else {
+        format!("{prefix}****")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn mask_short_values_are_fully_masked() {
+        assert_eq!(mask(""), "****");
+        assert_eq!(mask("abcd"), "****");
+    }
+
+    #[test]
+    fn mask_keeps_a_four_char_prefix() {
+        assert_eq!(mask("abcdefgh"), "abcd****");
+    }
+
+    #[test]
+    fn mask_splits_on_chars_not_bytes() {
+        // Each of these is a 3-byte UTF-8 character; byte-slicing the first
+        // 4 bytes would land mid-character and panic.
+        assert_eq!(mask("日本語能力試験"), "日本語能****");
+        assert_eq!(mask("日本語"), "****");
+    }
+}
+
+impl std::fmt::Display for Config {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        writeln!(
+            f,
+            "garmin_username = {}",
+            self.garmin_username.as_deref().unwrap_or("(unset)")
+        )?;
+        writeln!(
+            f,
+            "ai_provider = {}",
+            self.ai_provider.as_deref().unwrap_or("(unset)")
+        )?;
+        writeln!(
+            f,
+            "ai_api_key = {}",
+            self.ai_api_key.as_deref().map(mask).unwrap_or_else(|| "(unset)".to_string())
+        )?;
+        write!(
+            f,
+            "ai_model = {}",
+            self.ai_model.as_deref().unwrap_or("(unset)")
+        )
+    }
+}
@@ -1,6 +1,15 @@
-use clap::{Parser, Subcommand};
-use std::process::Command;
-use anyhow::{Result, Context};
+use clap::{Parser, Subcommand, ValueEnum};
+use std::ffi::OsStr;
+use anyhow::{Context, Result};
+
+mod activity;
+mod auth;
+mod cache;
+mod config;
+mod python;
+mod runner;
+
+use runner::{run_command, OutputMode};
 
 #[derive(Parser)]
 #[command(name = "garmin-coach")]
@@ -17,6 +26,9 @@ enum Commands {
         /// Type of data to fetch (activities, health, stats)
         #[arg(short, long, default_value = "activities")]
         data_type: String,
+        /// Output format
+        #[arg(short, long, value_enum, default_value_t = Format::Table)]
+        format: Format,
     },
     /// Get AI coaching feedback
     Coaching {
@@ -30,15 +42,43 @@ enum Commands {
         #[arg(short, long, default_value = "data")]
         example_type: String,
     },
+    /// Print the resolved configuration and where it was loaded from
+    Config,
+    /// Manage the stored Garmin Connect session token
+    Auth {
+        #[command(subcommand)]
+        action: AuthAction,
+    },
+}
+
+/// Output format for `FetchData`.
+#[derive(Clone, Copy, ValueEnum)]
+enum Format {
+    /// Human-readable aligned columns
+    Table,
+    /// Pretty-printed JSON
+    Json,
+    /// Comma-separated values
+    Csv,
+}
+
+#[derive(Subcommand)]
+enum AuthAction {
+    /// Prompt for credentials and store a session token
+    Login,
+    /// Remove the stored session token
+    Logout,
+    /// Show whether a session token is stored and its age
+    Status,
 }
 
 fn main() -> Result<()> {
     let cli = Cli::parse();
 
     match &cli.command {
-        Commands::FetchData { data_type } => {
+        Commands::FetchData { data_type, format } => {
             println!("Fetching {} data from Garmin Connect...", data_type);
-            fetch_garmin_data(data_type)?;
+            fetch_garmin_data(data_type, *format)?;
         }
         Commands::Coaching { coaching_type } => {
             println!("Getting {} coaching feedback...", coaching_type);
@@ -48,46 +88,114 @@ fn main() -> Result<()> {
             println!("Running {} example...", example_type);
             run_example(example_type)?;
         }
+        Commands::Config => {
+            print_config()?;
+        }
+        Commands::Auth { action } => match action {
+            AuthAction::Login => auth::login()?,
+            AuthAction::Logout => auth::logout()?,
+            AuthAction::Status => auth::status()?,
+        },
     }
 
     Ok(())
 }
 
-fn fetch_garmin_data(_data_type: &str) -> Result<()> {
+fn fetch_garmin_data(data_type: &str, format: Format) -> Result<()> {
     println!("\n📊 Fetching Garmin data using Python client...\n");
-    
-    let output = Command::new("python3")
-        .arg("python_client/example.py")
-        .output()
-        .context("Failed to execute Python script. Make sure Python 3 is installed.")?;
-
-    if output.status.success() {
-        println!("{}", String::from_utf8_lossy(&output.stdout));
-        println!("✅ Data fetched successfully!");
-    } else {
-        eprintln!("❌ Error fetching data:");
-        eprintln!("{}", String::from_utf8_lossy(&output.stderr));
-    }
 
+    let today = chrono::Local::now().date_naive().to_string();
+
+    let raw = match cache::load(data_type, &today)? {
+        Some(cached) => {
+            println!("(using cached {data_type} data from {today})");
+            cached
+        }
+        None => {
+            let python = python::find_python()?;
+            let loaded = config::load()?;
+            let env_vars = loaded.config.env_vars();
+            let env_refs: Vec<(&str, &str)> =
+                env_vars.iter().map(|(k, v)| (*k, v.as_str())).collect();
+            let output = run_command(
+                python.as_os_str(),
+                &[
+                    OsStr::new("python_client/example.py"),
+                    OsStr::new("--type"),
+                    OsStr::new(data_type),
+                    OsStr::new("--format"),
+                    OsStr::new("json"),
+                ],
+                None,
+                &env_refs,
+                OutputMode::Capture,
+            )?;
+            let raw = String::from_utf8(output.stdout)
+                .context("expected the Python client to emit UTF-8 JSON on stdout")?;
+            cache::store(data_type, &today, &raw)?;
+            raw
+        }
+    };
+
+    render(data_type, &raw, format)?;
+
+    println!("\n✅ Data fetched successfully!");
+
+    Ok(())
+}
+
+/// Parses `raw` JSON according to `data_type` and prints it in `format`.
+fn render(data_type: &str, raw: &str, format: Format) -> Result<()> {
+    match data_type {
+        "health" | "stats" => {
+            let stats: Vec<activity::HealthStats> = serde_json::from_str(raw)
+                .with_context(|| format!("expected '{data_type}' data as a JSON array of health stats"))?;
+            match format {
+                Format::Table => activity::print_health_table(&stats),
+                Format::Csv => activity::print_health_csv(&stats),
+                Format::Json => println!("{}", serde_json::to_string_pretty(&stats)?),
+            }
+        }
+        _ => {
+            let activities: Vec<activity::Activity> = serde_json::from_str(raw)
+                .with_context(|| format!("expected '{data_type}' data as a JSON array of activities"))?;
+            match format {
+                Format::Table => activity::print_activities_table(&activities),
+                Format::Csv => activity::print_activities_csv(&activities),
+                Format::Json => println!("{}", serde_json::to_string_pretty(&activities)?),
+            }
+        }
+    }
     Ok(())
 }
 
 fn get_ai_coaching(_coaching_type: &str) -> Result<()> {
     println!("\n🤖 Getting AI coaching feedback...\n");
-    
-    let output = Command::new("python3")
-        .arg("python_client/ai_example.py")
-        .output()
-        .context("Failed to execute AI coaching script. Make sure Python 3 is installed.")?;
-
-    if output.status.success() {
-        println!("{}", String::from_utf8_lossy(&output.stdout));
-        println!("✅ Coaching feedback received!");
-    } else {
-        eprintln!("❌ Error getting coaching:");
-        eprintln!("{}", String::from_utf8_lossy(&output.stderr));
+
+    let today = chrono::Local::now().date_naive().to_string();
+    let cached_activities = cache::load("activities", &today)?;
+    if cached_activities.is_some() {
+        println!("(reusing cached activities from {today})");
+    }
+
+    let python = python::find_python()?;
+    let loaded = config::load()?;
+    let env_vars = loaded.config.env_vars();
+    let mut env_refs: Vec<(&str, &str)> = env_vars.iter().map(|(k, v)| (*k, v.as_str())).collect();
+    if let Some(activities) = &cached_activities {
+        env_refs.push(("GARMIN_COACH_CACHED_ACTIVITIES", activities.as_str()));
     }
 
+    run_command(
+        python.as_os_str(),
+        &[OsStr::new("python_client/ai_example.py")],
+        None,
+        &env_refs,
+        OutputMode::Stream,
+    )?;
+
+    println!("✅ Coaching feedback received!");
+
     Ok(())
 }
 
@@ -102,18 +210,31 @@ fn run_example(example_type: &str) -> Result<()> {
     };
 
     println!("\n🚀 Running {} example...\n", example_type);
-    
-    let output = Command::new("python3")
-        .arg(script)
-        .output()
-        .context("Failed to execute example script. Make sure Python 3 is installed.")?;
-
-    if output.status.success() {
-        println!("{}", String::from_utf8_lossy(&output.stdout));
-    } else {
-        eprintln!("❌ Error running example:");
-        eprintln!("{}", String::from_utf8_lossy(&output.stderr));
+
+    let python = python::find_python()?;
+    let loaded = config::load()?;
+    let env_vars = loaded.config.env_vars();
+    let env_refs: Vec<(&str, &str)> = env_vars.iter().map(|(k, v)| (*k, v.as_str())).collect();
+    run_command(
+        python.as_os_str(),
+        &[OsStr::new(script)],
+        None,
+        &env_refs,
+        OutputMode::Stream,
+    )?;
+
+    Ok(())
+}
+
+fn print_config() -> Result<()> {
+    let loaded = config::load()?;
+
+    match &loaded.source {
+        Some(path) => println!("Loaded config from {}\n", path.display()),
+        None => println!("No config file found; using defaults\n"),
     }
 
+    println!("{}", loaded.config);
+
     Ok(())
 }
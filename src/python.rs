@@ -0,0 +1,141 @@
+use anyhow::{bail, Result};
+use std::env;
+use std::path::{Path, PathBuf};
+
+/// Interpreter names to probe on `PATH`, in priority order.
+const CANDIDATE_NAMES: &[&str] = &["python3", "python", "py"];
+
+/// Locates a usable Python interpreter.
+///
+/// Resolution order:
+/// 1. `GARMIN_COACH_PYTHON`, if set.
+/// 2. An activated virtualenv (`VIRTUAL_ENV`).
+/// 3. Candidate names (`python3`, `python`, `py`) found on `PATH`.
+pub fn find_python() -> Result<PathBuf> {
+    if let Ok(path) = env::var("GARMIN_COACH_PYTHON") {
+        let path = PathBuf::from(path);
+        if is_executable(&path) {
+            return Ok(path);
+        }
+        bail!(
+            "GARMIN_COACH_PYTHON is set to '{}' but it is not an executable file",
+            path.display()
+        );
+    }
+
+    if let Ok(venv) = env::var("VIRTUAL_ENV") {
+        let venv_python = venv_python_path(Path::new(&venv));
+        if is_executable(&venv_python) {
+            return Ok(venv_python);
+        }
+    }
+
+    for name in CANDIDATE_NAMES {
+        if let Some(path) = find_on_path(name) {
+            return Ok(path);
+        }
+    }
+
+    bail!(
+        "could not find a Python interpreter; tried GARMIN_COACH_PYTHON, VIRTUAL_ENV, and {} on PATH",
+        CANDIDATE_NAMES.join(", ")
+    );
+}
+
+fn venv_python_path(venv: &Path) -> PathBuf {
+    if cfg!(windows) {
+        venv.join("Scripts").join("python.exe")
+    } else {
+        venv.join("bin").join("python")
+    }
+}
+
+fn find_on_path(name: &str) -> Option<PathBuf> {
+    let path_var = env::var_os("PATH")?;
+    for dir in env::split_paths(&path_var) {
+        let candidate = dir.join(name);
+        if is_executable(&candidate) {
+            return Some(candidate);
+        }
+        if cfg!(windows) {
+            let with_ext = dir.join(format!("{name}.exe"));
+            if is_executable(&with_ext) {
+                return Some(with_ext);
+            }
+        }
+    }
+    None
+}
+
+#[cfg(unix)]
+fn is_executable(path: &Path) -> bool {
+    use std::os::unix::fs::PermissionsExt;
+    match std::fs::metadata(path) {
+        Ok(meta) => meta.is_file() && meta.permissions().mode() & 0o111 != 0,
+        Err(_) => false,
+    }
+}
+
+#[cfg(windows)]
+fn is_executable(path: &Path) -> bool {
+    if path.extension().is_some() {
+        return path.is_file();
+    }
+    let pathext = env::var("PATHEXT").unwrap_or_else(|_| ".EXE;.BAT;.CMD".to_string());
+    pathext
+        .split(';')
+        .any(|ext| path.with_extension(ext.trim_start_matches('.')).is_file())
+}
+
+#[cfg(all(test, unix))]
+mod tests {
+    use super::*;
+    use std::fs;
+    use std::os::unix::fs::PermissionsExt;
+    use std::sync::Mutex;
+
+    // `find_on_path` reads the process-wide `PATH`, so serialize tests that
+    // mutate it to avoid cross-test interference.
+    static PATH_LOCK: Mutex<()> = Mutex::new(());
+
+    fn make_executable(dir: &Path, name: &str) -> PathBuf {
+        let path = dir.join(name);
+        fs::write(&path, "#!/bin/sh\n").unwrap();
+        fs::set_permissions(&path, fs::Permissions::from_mode(0o755)).unwrap();
+        path
+    }
+
+    #[test]
+    fn find_on_path_respects_path_order() {
+        let _guard = PATH_LOCK.lock().unwrap();
+
+        let base = env::temp_dir().join(format!("garmin-coach-test-{}", std::process::id()));
+        let first = base.join("first");
+        let second = base.join("second");
+        fs::create_dir_all(&first).unwrap();
+        fs::create_dir_all(&second).unwrap();
+
+        let in_second_only = make_executable(&second, "python3");
+        let in_both_first = make_executable(&first, "python");
+        make_executable(&second, "python");
+
+        let original_path = env::var_os("PATH");
+        let joined = env::join_paths([&first, &second]).unwrap();
+        env::set_var("PATH", &joined);
+
+        let found_python3 = find_on_path("python3");
+        let found_python = find_on_path("python");
+        let found_missing = find_on_path("not-a-real-interpreter");
+
+        if let Some(path) = original_path {
+            env::set_var("PATH", path);
+        } else {
+            env::remove_var("PATH");
+        }
+        fs::remove_dir_all(&base).unwrap();
+
+        assert_eq!(found_python3, Some(in_second_only));
+        assert_eq!(found_python, Some(in_both_first));
+        assert_eq!(found_missing, None);
+    }
+}
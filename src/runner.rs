@@ -0,0 +1,138 @@
+use anyhow::{anyhow, Result};
+use std::ffi::OsStr;
+use std::io::{self, Read, Write};
+use std::path::Path;
+use std::process::{Command, Output, Stdio};
+use std::thread;
+
+/// How a command's stdout/stderr should be handled.
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub enum OutputMode {
+    /// Forward stdout/stderr to the terminal live as it arrives (e.g. a
+    /// streaming chat completion the user should watch trickle in).
+    Stream,
+    /// Capture stdout/stderr without printing them, for callers that only
+    /// want to parse or store the result (e.g. JSON payloads, auth tokens).
+    Capture,
+}
+
+/// Runs `program` with `args`, optionally in `cwd` and with extra `envs`,
+/// handling stdout/stderr according to `mode`.
+///
+/// The output is always accumulated and returned as an `Output`, so callers
+/// can post-process it regardless of mode. Unlike `Command::output`, a
+/// non-zero exit status is treated as an error: the returned `anyhow::Error`
+/// includes the joined command line, the working directory, and the exit
+/// code.
+pub fn run_command(
+    program: &OsStr,
+    args: &[&OsStr],
+    cwd: Option<&Path>,
+    envs: &[(&str, &str)],
+    mode: OutputMode,
+) -> Result<Output> {
+    let mut command = Command::new(program);
+    command.args(args);
+    if let Some(dir) = cwd {
+        command.current_dir(dir);
+    }
+    command.envs(envs.iter().copied());
+    command.stdout(Stdio::piped());
+    command.stderr(Stdio::piped());
+
+    let mut child = command.spawn()?;
+    let child_stdout = child.stdout.take().expect("stdout was piped");
+    let child_stderr = child.stderr.take().expect("stderr was piped");
+
+    let stdout_thread = thread::spawn(move || match mode {
+        OutputMode::Stream => forward_and_collect(child_stdout, Some(io::stdout())),
+        OutputMode::Capture => forward_and_collect(child_stdout, None),
+    });
+    let stderr_thread = thread::spawn(move || match mode {
+        OutputMode::Stream => forward_and_collect(child_stderr, Some(io::stderr())),
+        OutputMode::Capture => forward_and_collect(child_stderr, None),
+    });
+
+    let status = child.wait()?;
+    let stdout = stdout_thread
+        .join()
+        .map_err(|_| anyhow!("stdout forwarding thread panicked"))??;
+    let stderr = stderr_thread
+        .join()
+        .map_err(|_| anyhow!("stderr forwarding thread panicked"))??;
+
+    let output = Output {
+        status,
+        stdout,
+        stderr,
+    };
+    check_exit_status(program, args, cwd, &output)?;
+    Ok(output)
+}
+
+/// Reads all bytes from `source`, optionally echoing each chunk to `sink` as
+/// it arrives (flushing immediately so partial output shows up live), and
+/// returns everything read.
+fn forward_and_collect(mut source: impl Read, mut sink: Option<impl Write>) -> Result<Vec<u8>> {
+    let mut collected = Vec::new();
+    let mut buf = [0u8; 4096];
+    loop {
+        let read = source.read(&mut buf)?;
+        if read == 0 {
+            break;
+        }
+        if let Some(sink) = sink.as_mut() {
+            sink.write_all(&buf[..read])?;
+            sink.flush()?;
+        }
+        collected.extend_from_slice(&buf[..read]);
+    }
+    Ok(collected)
+}
+
+fn check_exit_status(
+    program: &OsStr,
+    args: &[&OsStr],
+    cwd: Option<&Path>,
+    output: &Output,
+) -> Result<()> {
+    if output.status.success() {
+        return Ok(());
+    }
+
+    Err(command_error(program, args, cwd, output))
+}
+
+fn command_error(
+    program: &OsStr,
+    args: &[&OsStr],
+    cwd: Option<&Path>,
+    output: &Output,
+) -> anyhow::Error {
+    let command_line = std::iter::once(program)
+        .chain(args.iter().copied())
+        .map(|part| part.to_string_lossy())
+        .collect::<Vec<_>>()
+        .join(" ");
+
+    let dir_display = cwd
+        .map(|dir| dir.display().to_string())
+        .unwrap_or_else(|| ".".to_string());
+
+    let exit_code = output
+        .status
+        .code()
+        .map(|code| code.to_string())
+        .unwrap_or_else(|| "terminated by signal".to_string());
+
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    let stderr = stderr.trim();
+
+    if stderr.is_empty() {
+        anyhow!("command `{command_line}` (cwd: {dir_display}) exited with {exit_code}")
+    } else {
+        anyhow!(
+            "command `{command_line}` (cwd: {dir_display}) exited with {exit_code}:\n{stderr}"
+        )
+    }
+}